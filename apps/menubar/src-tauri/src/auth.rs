@@ -0,0 +1,91 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use secrecy::{ExposeSecret, Secret};
+use sha2::{Digest, Sha256};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Cached result of loading the control-API secret, so repeated `ProxyClient`
+/// construction (nearly every refresh, switch, revert, hotkey press, and
+/// subscribe) doesn't re-read the secret file from disk each time.
+static SECRET: OnceLock<Result<Arc<Secret<String>>, String>> = OnceLock::new();
+
+/// Get the path to the per-install control-API signing secret
+fn secret_path() -> PathBuf {
+    let home = dirs::home_dir().expect("Cannot determine home directory");
+    home.join(".claude").join("oh-my-claude").join("control-secret")
+}
+
+/// Get the control-API signing secret, loading (and generating, on first run)
+/// it once and caching the result for the lifetime of the process.
+pub fn secret() -> Result<Arc<Secret<String>>, String> {
+    SECRET
+        .get_or_init(|| load_or_create_secret().map(Arc::new).map_err(|e| e.to_string()))
+        .clone()
+}
+
+/// Load the control-API signing secret, generating a new 256-bit one with 0600
+/// permissions on first run if the file doesn't exist yet.
+fn load_or_create_secret() -> std::io::Result<Secret<String>> {
+    let path = secret_path();
+
+    if let Ok(existing) = fs::read_to_string(&path) {
+        return Ok(Secret::new(existing.trim().to_string()));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let secret = hex::encode(bytes);
+
+    write_secret_file(&path, &secret)?;
+    Ok(Secret::new(secret))
+}
+
+#[cfg(unix)]
+fn write_secret_file(path: &PathBuf, secret: &str) -> std::io::Result<()> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(secret.as_bytes())
+}
+
+#[cfg(windows)]
+fn write_secret_file(path: &PathBuf, secret: &str) -> std::io::Result<()> {
+    // Windows ACLs don't map onto a Unix mode bit; the secret directory itself
+    // already lives under the user's profile, which is the best we can do here.
+    fs::write(path, secret)
+}
+
+/// Compute the hex HMAC-SHA256 signature for a signed control-API request, per
+/// `HMAC-SHA256(secret, "{METHOD}\n{PATH_AND_QUERY}\n{unix_millis}\n{sha256(body)}")`.
+pub fn sign(secret: &Secret<String>, method: &str, path_and_query: &str, unix_millis: u64, body: &[u8]) -> String {
+    let body_hash = hex::encode(Sha256::digest(body));
+    let message = format!("{}\n{}\n{}\n{}", method, path_and_query, unix_millis, body_hash);
+
+    let mut mac = HmacSha256::new_from_slice(secret.expose_secret().as_bytes())
+        .expect("HMAC-SHA256 accepts keys of any length");
+    mac.update(message.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Current Unix time in milliseconds, used as the signed request timestamp.
+pub fn unix_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        .as_millis() as u64
+}
@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+
+use crate::discover::discover_unregistered;
 use crate::proxy_client::ProxyClient;
 use crate::registry::read_registry;
-use crate::types::{ModelInfo, ProviderInfo, SessionInfo, SwitchResponse};
+use crate::types::{ProviderInfo, SessionInfo, SwitchResponse};
 
 /// List all active sessions with live status from their control APIs
 #[tauri::command]
@@ -11,7 +14,14 @@ pub async fn list_sessions() -> Vec<SessionInfo> {
 /// Inner implementation shared with tray menu refresh
 pub async fn list_sessions_inner() -> Vec<SessionInfo> {
     let entries = read_registry();
-    let client = ProxyClient::new();
+    let client = match ProxyClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("[commands] Failed to build proxy client: {}", e);
+            return Vec::new();
+        }
+    };
+    let registry_ports: HashSet<u16> = entries.iter().map(|e| e.control_port).collect();
     let mut sessions = Vec::new();
 
     for entry in entries {
@@ -53,6 +63,10 @@ pub async fn list_sessions_inner() -> Vec<SessionInfo> {
         });
     }
 
+    // Surface proxies that are listening but missing (or not yet written) from
+    // the registry, so a crashed writer doesn't make a running proxy invisible.
+    sessions.extend(discover_unregistered(&registry_ports, &client).await);
+
     sessions
 }
 
@@ -64,8 +78,11 @@ pub async fn switch_model(
     provider: String,
     model: String,
 ) -> Result<SwitchResponse, String> {
-    let client = ProxyClient::new();
-    client.switch_model(control_port, &session_id, &provider, &model).await
+    let client = ProxyClient::new()?;
+    client
+        .switch_model(control_port, &session_id, &provider, &model)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Revert a session to native Claude
@@ -74,8 +91,8 @@ pub async fn revert_model(
     control_port: u16,
     session_id: String,
 ) -> Result<SwitchResponse, String> {
-    let client = ProxyClient::new();
-    client.revert_model(control_port, &session_id).await
+    let client = ProxyClient::new()?;
+    client.revert_model(control_port, &session_id).await.map_err(|e| e.to_string())
 }
 
 /// Get available providers and their models
@@ -86,40 +103,5 @@ pub fn get_providers() -> Vec<ProviderInfo> {
 
 /// Inner implementation for provider list
 pub fn get_providers_inner() -> Vec<ProviderInfo> {
-    // Hardcoded provider/model list matching oh-my-claude config schema
-    // In future, read from ~/.claude/oh-my-claude.json
-    vec![
-        ProviderInfo {
-            name: "deepseek".to_string(),
-            models: vec![
-                ModelInfo { id: "deepseek-reasoner".to_string(), label: "DeepSeek Reasoner".to_string() },
-                ModelInfo { id: "deepseek-chat".to_string(), label: "DeepSeek Chat".to_string() },
-            ],
-        },
-        ProviderInfo {
-            name: "zhipu".to_string(),
-            models: vec![
-                ModelInfo { id: "GLM-5".to_string(), label: "ZhiPu GLM-5".to_string() },
-                ModelInfo { id: "glm-4v-flash".to_string(), label: "ZhiPu GLM-4V Flash".to_string() },
-            ],
-        },
-        ProviderInfo {
-            name: "minimax".to_string(),
-            models: vec![
-                ModelInfo { id: "MiniMax-M2.5".to_string(), label: "MiniMax M2.5".to_string() },
-            ],
-        },
-        ProviderInfo {
-            name: "kimi".to_string(),
-            models: vec![
-                ModelInfo { id: "K2.5".to_string(), label: "Kimi K2.5".to_string() },
-            ],
-        },
-        ProviderInfo {
-            name: "openai".to_string(),
-            models: vec![
-                ModelInfo { id: "gpt-5.3-codex".to_string(), label: "GPT-5.3 Codex".to_string() },
-            ],
-        },
-    ]
+    crate::providers::load_providers()
 }
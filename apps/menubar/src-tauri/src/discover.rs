@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures_util::{stream, StreamExt};
+
+use crate::proxy_client::ProxyClient;
+use crate::types::SessionInfo;
+
+/// Lowest port we bother probing. Loopback-bound ephemeral ports below this are
+/// vanishingly unlikely to be an oh-my-claude control API and not worth a round trip.
+const MIN_CANDIDATE_PORT: u16 = 1024;
+
+/// How often we actually re-scan for unregistered proxies. A scan means a
+/// signed HTTP probe against every other loopback-listening port on the
+/// machine (browsers, Docker, LSPs, editors, databases...); running it on
+/// every `list_sessions_inner` call — which now fires on every push event
+/// from any subscribed session — would reintroduce multi-second blocking and
+/// repeatedly hit unrelated local services. Scan on this cadence instead and
+/// serve cached results in between.
+const DISCOVERY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Cap on concurrent in-flight health probes during a single scan, so a
+/// machine with many open ports doesn't serialize dozens of 3s-timeout
+/// requests back to back.
+const MAX_CONCURRENT_PROBES: usize = 8;
+
+struct CachedDiscovery {
+    scanned_at: Instant,
+    sessions: Vec<SessionInfo>,
+}
+
+static CACHE: Mutex<Option<CachedDiscovery>> = Mutex::new(None);
+
+/// Enumerate local TCP sockets in `LISTEN` state on loopback. `netstat2`
+/// already abstracts the platform-specific syscalls (`getsockopt`-based
+/// enumeration on Unix, `GetExtendedTcpTable` on Windows) behind one
+/// cross-platform iterator, so a single implementation covers both.
+fn listening_loopback_ports() -> Vec<u16> {
+    use netstat2::{iterate_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, TcpState};
+
+    let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    let proto_flags = ProtocolFlags::TCP;
+
+    let sockets = match iterate_sockets_info(af_flags, proto_flags) {
+        Ok(iter) => iter,
+        Err(_) => return Vec::new(),
+    };
+
+    sockets
+        .filter_map(|info| info.ok())
+        .filter_map(|info| match info.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) if tcp.state == TcpState::Listen && tcp.local_addr.is_loopback() => {
+                Some(tcp.local_port)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Report loopback proxies that answer `/health` like an oh-my-claude control
+/// API but aren't in the registry, as "unregistered" sessions. Actually
+/// re-scans only every `DISCOVERY_INTERVAL`; in between, returns the last
+/// scan's results (minus anything that has since shown up in the registry).
+pub async fn discover_unregistered(registry_ports: &HashSet<u16>, client: &ProxyClient) -> Vec<SessionInfo> {
+    let needs_scan = match CACHE.lock().unwrap().as_ref() {
+        Some(cached) => cached.scanned_at.elapsed() >= DISCOVERY_INTERVAL,
+        None => true,
+    };
+
+    if needs_scan {
+        let sessions = scan(registry_ports, client).await;
+        *CACHE.lock().unwrap() = Some(CachedDiscovery { scanned_at: Instant::now(), sessions: sessions.clone() });
+        return sessions;
+    }
+
+    CACHE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|cached| {
+            cached
+                .sessions
+                .iter()
+                .filter(|s| !registry_ports.contains(&s.control_port))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Probe every loopback port not already covered by the registry, with at
+/// most `MAX_CONCURRENT_PROBES` requests in flight at once.
+async fn scan(registry_ports: &HashSet<u16>, client: &ProxyClient) -> Vec<SessionInfo> {
+    let candidates: Vec<u16> = listening_loopback_ports()
+        .into_iter()
+        .filter(|port| *port >= MIN_CANDIDATE_PORT && !registry_ports.contains(port))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    stream::iter(candidates)
+        .map(|port| async move { probe(port, client).await })
+        .buffer_unordered(MAX_CONCURRENT_PROBES)
+        .filter_map(|session| async move { session })
+        .collect()
+        .await
+}
+
+/// Probe a single candidate port, returning a synthetic "unregistered"
+/// session if it answers `/health` like an oh-my-claude control API.
+async fn probe(port: u16, client: &ProxyClient) -> Option<SessionInfo> {
+    if client.get_health(port).await.is_err() {
+        return None;
+    }
+
+    // No registry entry means no known session_id — the control port is the
+    // only stable handle we have for an unregistered session. No ':' in the ID,
+    // since tray menu actions split their fields on ':'.
+    let session_id = format!("unregistered-{}", port);
+    let (switched, provider, model) = match client.get_status(port, &session_id).await {
+        Ok(status) => (status.switched, status.provider, status.model),
+        Err(_) => (false, None, None),
+    };
+
+    Some(SessionInfo {
+        session_id,
+        port,
+        control_port: port,
+        pid: 0,
+        started_at: 0,
+        cwd: None,
+        project_name: "unregistered".to_string(),
+        switched,
+        provider,
+        model,
+        healthy: true,
+    })
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use serde::Deserialize;
+use tauri::{plugin::TauriPlugin, App, AppHandle, Wry};
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+
+use crate::commands::{get_providers_inner, list_sessions_inner};
+use crate::proxy_client::ProxyClient;
+use crate::tray::{notify, refresh_tray, short_session_id};
+use crate::types::SessionInfo;
+
+/// Default chord that reverts the active session if switched, or re-applies
+/// its last chosen provider/model if native — overridable via the `hotkeys`
+/// key in `~/.claude/oh-my-claude.json`.
+const DEFAULT_TOGGLE_MODIFIERS: Modifiers = Modifiers::SUPER.union(Modifiers::SHIFT);
+const DEFAULT_TOGGLE_CODE: Code = Code::KeyM;
+
+/// Default chord that cycles the active session forward through the catalog.
+const DEFAULT_CYCLE_MODIFIERS: Modifiers = Modifiers::SUPER.union(Modifiers::SHIFT);
+const DEFAULT_CYCLE_CODE: Code = Code::KeyN;
+
+/// `{ "hotkeys": { "toggle": "Super+Shift+M", "cycle": "Super+Shift+N" } }`
+/// inside the same user config file `providers` reads its catalog from.
+#[derive(Debug, Deserialize, Default)]
+struct HotkeyConfig {
+    toggle: Option<String>,
+    cycle: Option<String>,
+}
+
+/// Remembers the last provider/model chosen per session, so the toggle chord
+/// can re-apply it after a revert without the user reselecting from the menu.
+static LAST_CHOICE: Mutex<Option<HashMap<String, (String, String)>>> = Mutex::new(None);
+
+/// Resolved toggle/cycle chords, loaded once from the user config (falling
+/// back to the defaults above on a missing file or unparsable chord string).
+static SHORTCUTS: OnceLock<(Shortcut, Shortcut)> = OnceLock::new();
+
+fn configured_shortcuts() -> &'static (Shortcut, Shortcut) {
+    SHORTCUTS.get_or_init(|| {
+        let config = load_hotkey_config();
+        let toggle = resolve_shortcut(config.toggle.as_deref(), DEFAULT_TOGGLE_MODIFIERS, DEFAULT_TOGGLE_CODE);
+        let cycle = resolve_shortcut(config.cycle.as_deref(), DEFAULT_CYCLE_MODIFIERS, DEFAULT_CYCLE_CODE);
+        (toggle, cycle)
+    })
+}
+
+/// Get the path to the same user config file `providers` reads its catalog
+/// from — the `hotkeys` section lives alongside `providers` in one file.
+fn config_path() -> std::path::PathBuf {
+    let home = dirs::home_dir().expect("Cannot determine home directory");
+    home.join(".claude").join("oh-my-claude.json")
+}
+
+/// Read the `hotkeys` section of `~/.claude/oh-my-claude.json`, falling back
+/// to an empty config (and thus the hardcoded defaults) if the file is
+/// missing or the section fails to parse. A bad user config is logged, not
+/// panicked on.
+fn load_hotkey_config() -> HotkeyConfig {
+    let path = config_path();
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(_) => return HotkeyConfig::default(),
+    };
+
+    let value = match serde_json::from_str::<serde_json::Value>(&content) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("[hotkeys] Failed to parse {}: {}", path.display(), e);
+            return HotkeyConfig::default();
+        }
+    };
+
+    match value.get("hotkeys") {
+        Some(hotkeys) => serde_json::from_value(hotkeys.clone()).unwrap_or_else(|e| {
+            eprintln!("[hotkeys] Failed to parse \"hotkeys\" section of {}: {}", path.display(), e);
+            HotkeyConfig::default()
+        }),
+        None => HotkeyConfig::default(),
+    }
+}
+
+/// Parse a chord spec like `"Super+Shift+M"`, falling back to `default_mods`/
+/// `default_code` if it's absent or doesn't parse.
+fn resolve_shortcut(spec: Option<&str>, default_mods: Modifiers, default_code: Code) -> Shortcut {
+    if let Some(spec) = spec {
+        match parse_shortcut(spec) {
+            Some((mods, code)) => return Shortcut::new(Some(mods), code),
+            None => eprintln!("[hotkeys] Invalid shortcut {:?}, using default", spec),
+        }
+    }
+    Shortcut::new(Some(default_mods), default_code)
+}
+
+/// Parse a `+`-separated chord spec (e.g. `"CmdOrCtrl+Shift+M"`) into
+/// modifiers and a single trailing key code. Only bare letter keys (A-Z) are
+/// supported — enough for the toggle/cycle chords this module registers.
+fn parse_shortcut(spec: &str) -> Option<(Modifiers, Code)> {
+    let parts: Vec<&str> = spec.split('+').map(str::trim).collect();
+    let (key, modifier_names) = parts.split_last()?;
+
+    let mut mods = Modifiers::empty();
+    for name in modifier_names {
+        mods |= match name.to_ascii_lowercase().as_str() {
+            "cmdorctrl" | "commandorcontrol" | "super" | "cmd" | "command" | "meta" => Modifiers::SUPER,
+            "ctrl" | "control" => Modifiers::CONTROL,
+            "shift" => Modifiers::SHIFT,
+            "alt" | "option" => Modifiers::ALT,
+            _ => return None,
+        };
+    }
+
+    Some((mods, parse_key_code(key)?))
+}
+
+/// Single-letter key codes, indexed by `c - 'A'`.
+const LETTER_CODES: [Code; 26] = [
+    Code::KeyA, Code::KeyB, Code::KeyC, Code::KeyD, Code::KeyE, Code::KeyF, Code::KeyG, Code::KeyH, Code::KeyI,
+    Code::KeyJ, Code::KeyK, Code::KeyL, Code::KeyM, Code::KeyN, Code::KeyO, Code::KeyP, Code::KeyQ, Code::KeyR,
+    Code::KeyS, Code::KeyT, Code::KeyU, Code::KeyV, Code::KeyW, Code::KeyX, Code::KeyY, Code::KeyZ,
+];
+
+fn parse_key_code(key: &str) -> Option<Code> {
+    let mut chars = key.chars();
+    let c = chars.next()?.to_ascii_uppercase();
+    if chars.next().is_some() || !c.is_ascii_uppercase() {
+        return None;
+    }
+    Some(LETTER_CODES[(c as u8 - b'A') as usize])
+}
+
+/// Build the `tauri-plugin-global-shortcut` plugin with its key handler wired up.
+/// Call `register_shortcuts` from `setup` once the app handle exists to actually
+/// bind the chords.
+pub fn plugin() -> TauriPlugin<Wry> {
+    tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, shortcut, event| {
+            if event.state() != ShortcutState::Pressed {
+                return;
+            }
+
+            let (toggle, cycle) = configured_shortcuts();
+            let app_handle = app.clone();
+            if shortcut == toggle {
+                tauri::async_runtime::spawn(async move { toggle_active_session(&app_handle).await });
+            } else if shortcut == cycle {
+                tauri::async_runtime::spawn(async move { cycle_active_session(&app_handle).await });
+            }
+        })
+        .build()
+}
+
+/// Register the toggle and cycle chords with the OS.
+pub fn register_shortcuts(app: &App) -> Result<(), Box<dyn std::error::Error>> {
+    let (toggle, cycle) = configured_shortcuts();
+    app.global_shortcut().register(toggle.clone())?;
+    app.global_shortcut().register(cycle.clone())?;
+    Ok(())
+}
+
+/// The session a bare chord press should act on: the single session if only
+/// one is running, otherwise whichever was started most recently.
+fn resolve_active_session(sessions: &[SessionInfo]) -> Option<&SessionInfo> {
+    if sessions.len() == 1 {
+        return sessions.first();
+    }
+    sessions.iter().max_by_key(|s| s.started_at)
+}
+
+fn remember_choice(session_id: &str, provider: &str, model: &str) {
+    let mut guard = LAST_CHOICE.lock().unwrap();
+    guard
+        .get_or_insert_with(HashMap::new)
+        .insert(session_id.to_string(), (provider.to_string(), model.to_string()));
+}
+
+fn last_choice(session_id: &str) -> Option<(String, String)> {
+    LAST_CHOICE.lock().unwrap().as_ref()?.get(session_id).cloned()
+}
+
+/// Revert the active session to Claude if it's switched, or re-apply its last
+/// chosen provider/model if it's native.
+async fn toggle_active_session(app: &AppHandle) {
+    let sessions = list_sessions_inner().await;
+    let Some(session) = resolve_active_session(&sessions) else {
+        return;
+    };
+    let client = match ProxyClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("[hotkeys] Failed to build proxy client: {}", e);
+            return;
+        }
+    };
+
+    let short_id = short_session_id(&session.session_id);
+
+    if session.switched {
+        if let (Some(provider), Some(model)) = (&session.provider, &session.model) {
+            remember_choice(&session.session_id, provider, model);
+        }
+        match client.revert_model(session.control_port, &session.session_id).await {
+            Ok(_) => notify(app, "oh-my-claude", &format!("Session {} \u{2192} Claude (native)", short_id)),
+            Err(e) => notify(app, &format!("Couldn't revert session {}", short_id), &e.to_string()),
+        }
+    } else if let Some((provider, model)) = last_choice(&session.session_id) {
+        match client.switch_model(session.control_port, &session.session_id, &provider, &model).await {
+            Ok(resp) => {
+                notify(app, "oh-my-claude", &format!("Session {} \u{2192} {}/{}", short_id, provider, model));
+                if let Some(warning) = resp.warning {
+                    notify(app, "oh-my-claude warning", &warning);
+                }
+            }
+            Err(e) => notify(app, &format!("Couldn't switch session {}", short_id), &e.to_string()),
+        }
+    }
+
+    let _ = refresh_tray(app).await;
+}
+
+/// Switch the active session to the next provider/model in the catalog,
+/// wrapping around to the first entry past the end.
+async fn cycle_active_session(app: &AppHandle) {
+    let sessions = list_sessions_inner().await;
+    let Some(session) = resolve_active_session(&sessions) else {
+        return;
+    };
+
+    let catalog: Vec<(String, String)> = get_providers_inner()
+        .into_iter()
+        .flat_map(|p| p.models.into_iter().map(move |m| (p.name.clone(), m.id)))
+        .collect();
+    if catalog.is_empty() {
+        return;
+    }
+
+    let next = match (&session.provider, &session.model) {
+        (Some(provider), Some(model)) => catalog
+            .iter()
+            .position(|(p, m)| p == provider && m == model)
+            .map(|i| (i + 1) % catalog.len())
+            .unwrap_or(0),
+        _ => 0,
+    };
+    let (provider, model) = &catalog[next];
+
+    let client = match ProxyClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("[hotkeys] Failed to build proxy client: {}", e);
+            return;
+        }
+    };
+    let short_id = short_session_id(&session.session_id);
+    match client.switch_model(session.control_port, &session.session_id, provider, model).await {
+        Ok(resp) => {
+            remember_choice(&session.session_id, provider, model);
+            notify(app, "oh-my-claude", &format!("Session {} \u{2192} {}/{}", short_id, provider, model));
+            if let Some(warning) = resp.warning {
+                notify(app, "oh-my-claude warning", &warning);
+            }
+        }
+        Err(e) => notify(app, &format!("Couldn't switch session {}", short_id), &e.to_string()),
+    }
+
+    let _ = refresh_tray(app).await;
+}
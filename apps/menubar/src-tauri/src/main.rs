@@ -1,17 +1,60 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod auth;
 mod commands;
+mod discover;
+mod hotkeys;
 mod proxy_client;
+mod providers;
 mod registry;
 mod tray;
 mod types;
 
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
+use tauri::async_runtime::JoinHandle;
+
+use proxy_client::ProxyClient;
+use types::SessionEvent;
+
+/// How often we fall back to polling the registry file for new/removed sessions.
+/// Per-session status changes arrive over `ProxyClient::subscribe` instead; this
+/// poll also doubles as the only point that notices a session is gone, since
+/// `subscribe`'s reconnect loop never terminates on its own.
+const REGISTRY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Spawn a subscriber task for one session. The caller owns the returned
+/// handle and is responsible for aborting it once the session disappears
+/// from the registry — `subscribe`'s reconnect loop retries forever and has
+/// no way to notice that on its own.
+fn spawn_subscriber(app_handle: tauri::AppHandle, session_id: String, control_port: u16) -> JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let client = match ProxyClient::new() {
+            Ok(client) => client,
+            Err(e) => {
+                eprintln!("[main] Failed to build proxy client for {}: {}", session_id, e);
+                return;
+            }
+        };
+        let mut events = client.subscribe(control_port, &session_id);
+
+        while let Some(event) = events.recv().await {
+            match event {
+                SessionEvent::Status(_) | SessionEvent::Unhealthy | SessionEvent::Exited => {
+                    let _ = tray::refresh_tray(&app_handle).await;
+                }
+            }
+        }
+    })
+}
+
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(hotkeys::plugin())
         .invoke_handler(tauri::generate_handler![
             commands::list_sessions,
             commands::switch_model,
@@ -26,12 +69,41 @@ fn main() {
             // Set up system tray
             tray::setup_tray(app)?;
 
-            // Spawn background task to refresh tray every 2 seconds
+            // Bind the toggle/cycle global shortcuts
+            hotkeys::register_shortcuts(app)?;
+
+            // Spawn one subscriber task per registered session, driven by push
+            // events rather than busy-polling. A slower registry poll detects
+            // new/removed sessions so we know when to spawn or abort
+            // subscribers — the subscribe loop itself retries forever and
+            // can't tell on its own that a session is gone for good.
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
+                let mut subscribers: HashMap<String, JoinHandle<()>> = HashMap::new();
                 loop {
-                    tokio::time::sleep(Duration::from_secs(2)).await;
-                    let _ = tray::refresh_tray(&app_handle).await;
+                    let entries = registry::read_registry();
+                    let live: HashSet<&str> = entries.iter().map(|e| e.session_id.as_str()).collect();
+
+                    for entry in &entries {
+                        if !subscribers.contains_key(&entry.session_id) {
+                            let handle = spawn_subscriber(
+                                app_handle.clone(),
+                                entry.session_id.clone(),
+                                entry.control_port,
+                            );
+                            subscribers.insert(entry.session_id.clone(), handle);
+                        }
+                    }
+
+                    subscribers.retain(|session_id, handle| {
+                        let keep = live.contains(session_id.as_str());
+                        if !keep {
+                            handle.abort();
+                        }
+                        keep
+                    });
+
+                    tokio::time::sleep(REGISTRY_POLL_INTERVAL).await;
                 }
             });
 
@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use crate::types::{ModelInfo, ProviderInfo};
+
+/// Get the path to the user's provider/model catalog
+fn config_path() -> PathBuf {
+    let home = dirs::home_dir().expect("Cannot determine home directory");
+    home.join(".claude").join("oh-my-claude.json")
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfigFile {
+    providers: Vec<ConfigProvider>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfigProvider {
+    name: String,
+    models: Vec<ConfigModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfigModel {
+    id: String,
+    label: String,
+}
+
+/// mtime of the config file as of the last `config_changed` check, used to
+/// notice edits without reparsing the file on every refresh.
+static LAST_MTIME: Mutex<Option<SystemTime>> = Mutex::new(None);
+
+/// Load the provider/model catalog from `~/.claude/oh-my-claude.json`, falling
+/// back to the built-in list if the file is missing or fails to parse. A bad
+/// user config is logged, not panicked on.
+pub fn load_providers() -> Vec<ProviderInfo> {
+    let path = config_path();
+    let content = match fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(_) => return builtin_providers(),
+    };
+
+    match serde_json::from_str::<ConfigFile>(&content) {
+        Ok(cfg) => cfg
+            .providers
+            .into_iter()
+            .map(|p| ProviderInfo {
+                name: p.name,
+                models: p.models.into_iter().map(|m| ModelInfo { id: m.id, label: m.label }).collect(),
+            })
+            .collect(),
+        Err(e) => {
+            eprintln!("[providers] Failed to parse {}: {}", path.display(), e);
+            builtin_providers()
+        }
+    }
+}
+
+/// Whether the config file's mtime has changed since the last call. The tray
+/// refresh path calls this on every `refresh_tray` call so edits to the catalog
+/// repopulate session submenus on the next refresh without restarting the app.
+pub fn config_changed() -> bool {
+    let mtime = fs::metadata(config_path()).and_then(|m| m.modified()).ok();
+    let mut last = LAST_MTIME.lock().unwrap();
+    let changed = *last != mtime;
+    *last = mtime;
+    changed
+}
+
+/// Built-in provider/model catalog, used when `~/.claude/oh-my-claude.json`
+/// is missing or malformed.
+fn builtin_providers() -> Vec<ProviderInfo> {
+    vec![
+        ProviderInfo {
+            name: "deepseek".to_string(),
+            models: vec![
+                ModelInfo { id: "deepseek-reasoner".to_string(), label: "DeepSeek Reasoner".to_string() },
+                ModelInfo { id: "deepseek-chat".to_string(), label: "DeepSeek Chat".to_string() },
+            ],
+        },
+        ProviderInfo {
+            name: "zhipu".to_string(),
+            models: vec![
+                ModelInfo { id: "GLM-5".to_string(), label: "ZhiPu GLM-5".to_string() },
+                ModelInfo { id: "glm-4v-flash".to_string(), label: "ZhiPu GLM-4V Flash".to_string() },
+            ],
+        },
+        ProviderInfo {
+            name: "minimax".to_string(),
+            models: vec![
+                ModelInfo { id: "MiniMax-M2.5".to_string(), label: "MiniMax M2.5".to_string() },
+            ],
+        },
+        ProviderInfo {
+            name: "kimi".to_string(),
+            models: vec![
+                ModelInfo { id: "K2.5".to_string(), label: "Kimi K2.5".to_string() },
+            ],
+        },
+        ProviderInfo {
+            name: "openai".to_string(),
+            models: vec![
+                ModelInfo { id: "gpt-5.3-codex".to_string(), label: "GPT-5.3 Codex".to_string() },
+            ],
+        },
+    ]
+}
@@ -1,33 +1,82 @@
+use std::time::Duration;
+
+use async_tungstenite::tokio::connect_async;
+use async_tungstenite::tungstenite::handshake::client::generate_key;
+use async_tungstenite::tungstenite::Message;
+use futures_util::StreamExt;
 use reqwest::Client;
-use crate::types::{HealthResponse, StatusResponse, SwitchRequest, SwitchResponse};
+use secrecy::Secret;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use crate::auth;
+use crate::types::{HealthResponse, SessionEvent, StatusResponse, SwitchRequest, SwitchResponse};
+
+/// Initial and maximum backoff between reconnect attempts in `subscribe`.
+const SUBSCRIBE_BACKOFF_START: Duration = Duration::from_millis(250);
+const SUBSCRIBE_BACKOFF_MAX: Duration = Duration::from_secs(10);
+
+/// Distinguishes a proxy that actively rejected a request from one that
+/// couldn't be reached at all, so callers can tell users apart accordingly.
+#[derive(Debug, Clone)]
+pub enum ProxyError {
+    /// Connection refused, timed out, or otherwise never got an HTTP response;
+    /// also used when the request body can't be encoded or a 2xx response
+    /// body can't be decoded.
+    Unreachable(String),
+    /// The proxy responded with a non-2xx status — it saw the request and said no.
+    Denied(String),
+}
+
+impl std::fmt::Display for ProxyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProxyError::Unreachable(msg) => write!(f, "proxy unreachable: {}", msg),
+            ProxyError::Denied(msg) => write!(f, "request denied by proxy: {}", msg),
+        }
+    }
+}
 
-/// HTTP client for communicating with per-session proxy control APIs
+/// HTTP client for communicating with per-session proxy control APIs.
+/// Every request is signed with the per-install control secret so that only
+/// this process (not an arbitrary localhost caller) can switch or revert a
+/// session's model.
 pub struct ProxyClient {
     client: Client,
+    secret: Arc<Secret<String>>,
 }
 
 impl ProxyClient {
-    pub fn new() -> Self {
-        Self {
-            client: Client::builder()
-                .timeout(std::time::Duration::from_secs(3))
-                .build()
-                .expect("Failed to build HTTP client"),
-        }
+    /// Build a client, loading the cached control-API secret. Fails if the
+    /// secret can't be read or created (e.g. a permissions issue under
+    /// `~/.claude/oh-my-claude/`) — callers decide how to surface that rather
+    /// than the whole process panicking on it.
+    pub fn new() -> Result<Self, String> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(3))
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self { client, secret: auth::secret()? })
+    }
+
+    /// Sign a request and attach the `X-OMC-Signature`/`X-OMC-Timestamp` headers.
+    fn signed(&self, builder: reqwest::RequestBuilder, method: &str, path_and_query: &str, body: &[u8]) -> reqwest::RequestBuilder {
+        let ts = auth::unix_millis();
+        let signature = auth::sign(&self.secret, method, path_and_query, ts, body);
+        builder
+            .header("X-OMC-Timestamp", ts.to_string())
+            .header("X-OMC-Signature", signature)
     }
 
     /// GET /health on a control port
     pub async fn get_health(&self, control_port: u16) -> Result<HealthResponse, String> {
-        let url = format!("http://localhost:{}/health", control_port);
-        let resp = self.client.get(&url).send().await.map_err(|e| e.to_string())?;
-        resp.json().await.map_err(|e| e.to_string())
+        signed_get(&self.client, &self.secret, control_port, "/health").await
     }
 
     /// GET /status?session=ID on a control port
     pub async fn get_status(&self, control_port: u16, session_id: &str) -> Result<StatusResponse, String> {
-        let url = format!("http://localhost:{}/status?session={}", control_port, session_id);
-        let resp = self.client.get(&url).send().await.map_err(|e| e.to_string())?;
-        resp.json().await.map_err(|e| e.to_string())
+        let path_and_query = format!("/status?session={}", session_id);
+        signed_get(&self.client, &self.secret, control_port, &path_and_query).await
     }
 
     /// POST /switch?session=ID on a control port
@@ -37,14 +86,23 @@ impl ProxyClient {
         session_id: &str,
         provider: &str,
         model: &str,
-    ) -> Result<SwitchResponse, String> {
-        let url = format!("http://localhost:{}/switch?session={}", control_port, session_id);
-        let body = SwitchRequest {
+    ) -> Result<SwitchResponse, ProxyError> {
+        let path_and_query = format!("/switch?session={}", session_id);
+        let url = format!("http://localhost:{}{}", control_port, path_and_query);
+        let body = serde_json::to_vec(&SwitchRequest {
             provider: provider.to_string(),
             model: model.to_string(),
-        };
-        let resp = self.client.post(&url).json(&body).send().await.map_err(|e| e.to_string())?;
-        resp.json().await.map_err(|e| e.to_string())
+        })
+        .map_err(|e| ProxyError::Unreachable(e.to_string()))?;
+
+        let req = self.signed(self.client.post(&url), "POST", &path_and_query, &body);
+        let resp = req
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| ProxyError::Unreachable(e.to_string()))?;
+        parse_proxy_response(resp).await
     }
 
     /// POST /revert?session=ID on a control port
@@ -52,9 +110,137 @@ impl ProxyClient {
         &self,
         control_port: u16,
         session_id: &str,
-    ) -> Result<SwitchResponse, String> {
-        let url = format!("http://localhost:{}/revert?session={}", control_port, session_id);
-        let resp = self.client.post(&url).send().await.map_err(|e| e.to_string())?;
-        resp.json().await.map_err(|e| e.to_string())
+    ) -> Result<SwitchResponse, ProxyError> {
+        let path_and_query = format!("/revert?session={}", session_id);
+        let url = format!("http://localhost:{}{}", control_port, path_and_query);
+        let req = self.signed(self.client.post(&url), "POST", &path_and_query, b"");
+        let resp = req.send().await.map_err(|e| ProxyError::Unreachable(e.to_string()))?;
+        parse_proxy_response(resp).await
+    }
+
+    /// Open a persistent `/events` subscription for a session, reconnecting with
+    /// capped exponential backoff (250ms doubling to 10s) whenever the socket drops.
+    /// A dropped or unreachable socket is reported as `SessionEvent::Exited` so the
+    /// caller can treat it the same as the proxy actually exiting. Every successful
+    /// connect seeds the caller with the session's current state immediately,
+    /// rather than waiting for the next server push, so an already-running,
+    /// healthy session shows up right away instead of only on its next change.
+    pub fn subscribe(&self, control_port: u16, session_id: &str) -> mpsc::Receiver<SessionEvent> {
+        let (tx, rx) = mpsc::channel(16);
+        let session_id = session_id.to_string();
+        let secret = self.secret.clone();
+        let client = self.client.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let path_and_query = format!("/events?session={}", session_id);
+            let status_path_and_query = format!("/status?session={}", session_id);
+            let mut backoff = SUBSCRIBE_BACKOFF_START;
+
+            loop {
+                let handshake = signed_handshake_request(control_port, &path_and_query, &secret);
+                if let Ok(request) = handshake {
+                    if let Ok((ws_stream, _)) = connect_async(request).await {
+                        backoff = SUBSCRIBE_BACKOFF_START;
+
+                        // Seed the caller with current state right away — the proxy
+                        // is reachable, but a healthy, unswitched session otherwise
+                        // never pushes anything over this socket.
+                        let seed = signed_get::<StatusResponse>(&client, &secret, control_port, &status_path_and_query)
+                            .await
+                            .map(SessionEvent::Status)
+                            .unwrap_or(SessionEvent::Unhealthy);
+                        if tx.send(seed).await.is_err() {
+                            return;
+                        }
+
+                        let (_, mut read) = ws_stream.split();
+
+                        while let Some(msg) = read.next().await {
+                            let event = match msg {
+                                Ok(Message::Text(text)) => {
+                                    serde_json::from_str::<StatusResponse>(&text).ok().map(SessionEvent::Status)
+                                }
+                                Ok(Message::Close(_)) | Err(_) => break,
+                                _ => None,
+                            };
+
+                            if let Some(event) = event {
+                                if tx.send(event).await.is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Socket never connected or just dropped — the caller should treat
+                // the session as gone until a reconnect proves otherwise.
+                if tx.send(SessionEvent::Exited).await.is_err() {
+                    return;
+                }
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(SUBSCRIBE_BACKOFF_MAX);
+            }
+        });
+
+        rx
+    }
+}
+
+/// Sign and send a GET request against a control port, independent of a
+/// `ProxyClient` instance — used both by `ProxyClient::get_health`/`get_status`
+/// and by the standalone `subscribe` task, which only has a cloned `Client`
+/// and secret rather than `&self`.
+async fn signed_get<T: serde::de::DeserializeOwned>(
+    client: &Client,
+    secret: &Secret<String>,
+    control_port: u16,
+    path_and_query: &str,
+) -> Result<T, String> {
+    let ts = auth::unix_millis();
+    let signature = auth::sign(secret, "GET", path_and_query, ts, b"");
+    let url = format!("http://localhost:{}{}", control_port, path_and_query);
+
+    let resp = client
+        .get(&url)
+        .header("X-OMC-Timestamp", ts.to_string())
+        .header("X-OMC-Signature", signature)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    resp.json().await.map_err(|e| e.to_string())
+}
+
+/// Turn a switch/revert response into `Ok` on 2xx and `ProxyError::Denied` on
+/// any other status, carrying the proxy's JSON error body as the message.
+async fn parse_proxy_response(resp: reqwest::Response) -> Result<SwitchResponse, ProxyError> {
+    if !resp.status().is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(ProxyError::Denied(body));
     }
+    resp.json().await.map_err(|e| ProxyError::Unreachable(e.to_string()))
+}
+
+/// Build the signed WebSocket upgrade request for `/events`. The signature
+/// covers the HTTP method of the upgrade ("GET") and the empty body, same as
+/// any other unsigned-body GET.
+fn signed_handshake_request(
+    control_port: u16,
+    path_and_query: &str,
+    secret: &Secret<String>,
+) -> Result<http::Request<()>, http::Error> {
+    let ts = auth::unix_millis();
+    let signature = auth::sign(secret, "GET", path_and_query, ts, b"");
+    let url = format!("ws://localhost:{}{}", control_port, path_and_query);
+
+    http::Request::builder()
+        .uri(url)
+        .header("Host", format!("localhost:{}", control_port))
+        .header("Upgrade", "websocket")
+        .header("Connection", "Upgrade")
+        .header("Sec-WebSocket-Key", generate_key())
+        .header("Sec-WebSocket-Version", "13")
+        .header("X-OMC-Timestamp", ts.to_string())
+        .header("X-OMC-Signature", signature)
+        .body(())
 }
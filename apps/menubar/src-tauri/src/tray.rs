@@ -9,6 +9,7 @@ use tauri::{
     tray::TrayIconBuilder,
     App,
 };
+use tauri_plugin_notification::NotificationExt;
 
 use crate::proxy_client::ProxyClient;
 
@@ -84,7 +85,14 @@ fn handle_menu_event(app: &tauri::AppHandle, id: &str) {
 async fn handle_action(app: &tauri::AppHandle, id: &str) {
     // Strip the _N counter suffix from the last segment
     let id = strip_counter_suffix(id);
-    let client = ProxyClient::new();
+    let client = match ProxyClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("[tray] Failed to build proxy client: {}", e);
+            notify(app, "oh-my-claude", &format!("Couldn't reach proxy: {}", e));
+            return;
+        }
+    };
     let parts: Vec<&str> = id.split(':').collect();
 
     match parts[0] {
@@ -93,24 +101,54 @@ async fn handle_action(app: &tauri::AppHandle, id: &str) {
             let session_id = parts[2];
             let provider = parts[3];
             let model = parts[4];
-                match client.switch_model(control_port, session_id, provider, model).await {
-                Ok(_) => { let _ = refresh_tray(app).await; }
-                Err(e) => eprintln!("[tray] Switch error: {}", e),
+            let short_id = short_session_id(session_id);
+
+            match client.switch_model(control_port, session_id, provider, model).await {
+                Ok(resp) => {
+                    notify(app, "oh-my-claude", &format!("Session {} \u{2192} {}/{}", short_id, provider, model));
+                    if let Some(warning) = resp.warning {
+                        notify(app, "oh-my-claude warning", &warning);
+                    }
+                    let _ = refresh_tray(app).await;
+                }
+                Err(e) => {
+                    eprintln!("[tray] Switch error: {}", e);
+                    notify(app, &format!("Couldn't switch session {}", short_id), &e.to_string());
+                }
             }
         }
         "revert" if parts.len() >= 3 => {
             let control_port: u16 = parts[1].parse().unwrap_or(0);
             let session_id = parts[2];
+            let short_id = short_session_id(session_id);
 
             match client.revert_model(control_port, session_id).await {
-                Ok(_) => { let _ = refresh_tray(app).await; }
-                Err(e) => eprintln!("[tray] Revert error: {}", e),
+                Ok(_) => {
+                    notify(app, "oh-my-claude", &format!("Session {} \u{2192} Claude (native)", short_id));
+                    let _ = refresh_tray(app).await;
+                }
+                Err(e) => {
+                    eprintln!("[tray] Revert error: {}", e);
+                    notify(app, &format!("Couldn't revert session {}", short_id), &e.to_string());
+                }
             }
         }
         _ => {}
     }
 }
 
+/// Truncate a session ID to the same 8-char prefix used in menu labels.
+pub(crate) fn short_session_id(session_id: &str) -> &str {
+    &session_id[..session_id.len().min(8)]
+}
+
+/// Show a native notification, swallowing errors — a failed toast shouldn't
+/// take down the switch/revert flow that triggered it. Shared with `hotkeys`
+/// so the global-shortcut path gives the same feedback as the tray menu.
+pub(crate) fn notify(app: &tauri::AppHandle, title: &str, body: &str) {
+    let _ = app.notification().builder().title(title).body(body).show();
+}
+
 /// Strip the `_N` counter suffix from a menu item ID.
 /// e.g. `"switch:9000:abc:deepseek:chat:-1_42"` → `"switch:9000:abc:deepseek:chat:-1"`
 fn strip_counter_suffix(id: &str) -> String {
@@ -147,11 +185,15 @@ fn compute_fingerprint(sessions: &[crate::types::SessionInfo]) -> String {
 pub async fn refresh_tray(app: &tauri::AppHandle) -> Result<(), String> {
     let sessions = crate::commands::list_sessions_inner().await;
 
+    // A provider catalog edit doesn't touch session state, so it must force a
+    // rebuild on its own even if the session fingerprint below is unchanged.
+    let config_changed = crate::providers::config_changed();
+
     // Skip rebuild if nothing changed
     let fingerprint = compute_fingerprint(&sessions);
     {
         let mut last = LAST_MENU_FINGERPRINT.lock().unwrap();
-        if *last == fingerprint {
+        if *last == fingerprint && !config_changed {
             return Ok(());
         }
         *last = fingerprint;
@@ -180,11 +222,7 @@ pub async fn refresh_tray(app: &tauri::AppHandle) -> Result<(), String> {
                 "Claude (native)".to_string()
             };
 
-            let session_label = format!(
-                "{} - {}",
-                &session.session_id[..session.session_id.len().min(8)],
-                current_model
-            );
+            let session_label = format!("{} - {}", short_session_id(&session.session_id), current_model);
 
             // Create submenu for this session — each needs a unique ID
             let submenu = Submenu::with_id(
@@ -58,6 +58,19 @@ pub struct SwitchRequest {
     pub model: String,
 }
 
+/// An event yielded by `ProxyClient::subscribe` for a session.
+/// `Status` carries a message pushed over `/events` (or the seed `/status`
+/// query on connect), in the same shape a `/status` poll returns. `Unhealthy`
+/// (the seed `/status` query failed) and `Exited` (the socket failed to
+/// connect or dropped) are generated by the client and have no payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum SessionEvent {
+    Status(StatusResponse),
+    Unhealthy,
+    Exited,
+}
+
 /// Switch/revert response from proxy control API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]